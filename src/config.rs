@@ -0,0 +1,166 @@
+//! Poller configuration: refresh cadence, per-request timeout, and the
+//! reconnect backoff policy. Loaded once from a config file at startup, then
+//! shared with every poller thread behind a mutex so UI controls can tweak
+//! it live for long-running monitoring sessions.
+
+use std::{
+    fs,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+const CONFIG_FILE: &str = "quirky_binder_capnp_client.conf";
+
+#[derive(Clone, Copy)]
+pub struct PollerConfig {
+    pub refresh_interval_ms: u64,
+    pub request_timeout_ms: u64,
+    pub reconnect_initial_backoff_ms: u64,
+    pub reconnect_max_backoff_ms: u64,
+    pub shutdown_grace_ms: u64,
+}
+
+impl Default for PollerConfig {
+    fn default() -> Self {
+        Self {
+            refresh_interval_ms: 3000,
+            request_timeout_ms: 5000,
+            reconnect_initial_backoff_ms: 500,
+            reconnect_max_backoff_ms: 30_000,
+            shutdown_grace_ms: 3000,
+        }
+    }
+}
+
+impl PollerConfig {
+    pub fn refresh_interval(&self) -> Duration {
+        Duration::from_millis(self.refresh_interval_ms)
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+
+    pub fn reconnect_initial_backoff(&self) -> Duration {
+        Duration::from_millis(self.reconnect_initial_backoff_ms)
+    }
+
+    pub fn reconnect_max_backoff(&self) -> Duration {
+        Duration::from_millis(self.reconnect_max_backoff_ms)
+    }
+
+    pub fn shutdown_grace(&self) -> Duration {
+        Duration::from_millis(self.shutdown_grace_ms)
+    }
+}
+
+pub type SharedPollerConfig = Arc<Mutex<PollerConfig>>;
+
+/// Loads `CONFIG_FILE` (simple `key = value` lines) from the current
+/// directory if present, falling back to defaults for anything missing or
+/// unparsable.
+pub fn load() -> PollerConfig {
+    match fs::read_to_string(CONFIG_FILE) {
+        Ok(contents) => parse(&contents),
+        Err(_) => PollerConfig::default(),
+    }
+}
+
+/// Parses `key = value` lines into a config, starting from the defaults.
+/// Blank lines, `#` comments, malformed lines, and unknown or unparsable
+/// keys are silently ignored so a partial or stale config file still loads.
+fn parse(contents: &str) -> PollerConfig {
+    let mut config = PollerConfig::default();
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let Ok(millis) = value.trim().parse::<u64>() else {
+            continue;
+        };
+        match key.trim() {
+            "refresh_interval_ms" => config.refresh_interval_ms = millis,
+            "request_timeout_ms" => config.request_timeout_ms = millis,
+            "reconnect_initial_backoff_ms" => config.reconnect_initial_backoff_ms = millis,
+            "reconnect_max_backoff_ms" => config.reconnect_max_backoff_ms = millis,
+            "shutdown_grace_ms" => config.shutdown_grace_ms = millis,
+            _ => {}
+        }
+    }
+
+    config
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_contents_yields_defaults() {
+        let config = parse("");
+        assert_eq!(
+            config.refresh_interval_ms,
+            PollerConfig::default().refresh_interval_ms
+        );
+    }
+
+    #[test]
+    fn parses_all_known_keys() {
+        let config = parse(
+            "refresh_interval_ms = 1000\n\
+             request_timeout_ms = 2000\n\
+             reconnect_initial_backoff_ms = 100\n\
+             reconnect_max_backoff_ms = 5000\n\
+             shutdown_grace_ms = 500\n",
+        );
+        assert_eq!(config.refresh_interval_ms, 1000);
+        assert_eq!(config.request_timeout_ms, 2000);
+        assert_eq!(config.reconnect_initial_backoff_ms, 100);
+        assert_eq!(config.reconnect_max_backoff_ms, 5000);
+        assert_eq!(config.shutdown_grace_ms, 500);
+    }
+
+    #[test]
+    fn ignores_comments_and_blank_lines() {
+        let config = parse("# a comment\n\n  \nrefresh_interval_ms = 42\n");
+        assert_eq!(config.refresh_interval_ms, 42);
+    }
+
+    #[test]
+    fn ignores_lines_without_an_equals_sign() {
+        let config = parse("refresh_interval_ms\n");
+        assert_eq!(
+            config.refresh_interval_ms,
+            PollerConfig::default().refresh_interval_ms
+        );
+    }
+
+    #[test]
+    fn ignores_non_numeric_values() {
+        let config = parse("refresh_interval_ms = soon\n");
+        assert_eq!(
+            config.refresh_interval_ms,
+            PollerConfig::default().refresh_interval_ms
+        );
+    }
+
+    #[test]
+    fn ignores_unknown_keys() {
+        let config = parse("not_a_real_key = 42\n");
+        assert_eq!(
+            config.refresh_interval_ms,
+            PollerConfig::default().refresh_interval_ms
+        );
+    }
+
+    #[test]
+    fn trims_whitespace_around_keys_and_values() {
+        let config = parse("  refresh_interval_ms   =   42  \n");
+        assert_eq!(config.refresh_interval_ms, 42);
+    }
+}
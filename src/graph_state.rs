@@ -0,0 +1,40 @@
+//! The per-refresh snapshot handed from `state_poller` to the UI thread: the
+//! rendered graph plus enough of the decoded `node_statuses` to drive the
+//! node detail side panel without re-querying the service.
+
+use std::collections::BTreeMap;
+
+use crate::layout::NodeBox;
+
+/// The lifecycle of a connection's poller thread, surfaced in the tab title
+/// so a wedged or restarting process is visible instead of silently hanging.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionStatus {
+    Connecting,
+    Connected,
+    Reconnecting,
+    Disconnected,
+}
+
+pub struct NodeStatus {
+    pub input_read: Vec<u64>,
+    pub output_written: Vec<u64>,
+    /// Recent items/sec throughput samples (oldest first), for the detail
+    /// panel sparkline.
+    pub rate_history: Vec<f64>,
+}
+
+pub struct EdgeInfo {
+    pub tail: String,
+    pub tail_index: u32,
+    pub head: String,
+    pub head_index: u32,
+    pub rate: Option<f64>,
+}
+
+pub struct GraphUpdate {
+    pub svg: String,
+    pub node_boxes: Vec<NodeBox>,
+    pub statuses: BTreeMap<String, NodeStatus>,
+    pub edges: Vec<EdgeInfo>,
+}
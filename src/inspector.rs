@@ -0,0 +1,233 @@
+//! Ring-buffer capture of Cap'n Proto request/response traffic, used to back
+//! the inspector dock tab so protocol issues can be diagnosed live instead of
+//! only seeing the rendered graph.
+
+use std::{collections::VecDeque, fmt::Write, time::Instant};
+
+use quirky_binder_capnp::quirky_binder_capnp;
+
+/// Caps memory use for long-running monitoring sessions; old entries are
+/// dropped once the buffer is full.
+const MAX_MESSAGES: usize = 200;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Sent,
+    Received,
+}
+
+pub struct RecordedMessage {
+    /// Stable identity, unaffected by eviction from the ring buffer; lets
+    /// `Inspector::selected` keep pointing at the same message instead of
+    /// drifting as older entries are popped off the front.
+    pub id: u64,
+    pub timestamp: Instant,
+    pub direction: Direction,
+    pub name: &'static str,
+    pub byte_size: usize,
+    pub tree: String,
+}
+
+/// Sent from the poller thread to the UI thread for every request and the
+/// response that follows it.
+pub struct InspectorEvent {
+    pub direction: Direction,
+    pub name: &'static str,
+    pub byte_size: usize,
+    pub tree: String,
+}
+
+impl InspectorEvent {
+    fn into_message(self, id: u64) -> RecordedMessage {
+        RecordedMessage {
+            id,
+            timestamp: Instant::now(),
+            direction: self.direction,
+            name: self.name,
+            byte_size: self.byte_size,
+            tree: self.tree,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Inspector {
+    messages: VecDeque<RecordedMessage>,
+    next_id: u64,
+    pub frozen: bool,
+    pub filter: String,
+    pub selected: Option<u64>,
+}
+
+impl Inspector {
+    pub fn push(&mut self, event: InspectorEvent) {
+        if self.frozen {
+            return;
+        }
+        if self.messages.len() >= MAX_MESSAGES {
+            self.messages.pop_front();
+        }
+        let id = self.next_id;
+        self.next_id += 1;
+        self.messages.push_back(event.into_message(id));
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &RecordedMessage> {
+        self.messages.iter().filter(move |m| {
+            self.filter.is_empty() || m.name.to_lowercase().contains(&self.filter.to_lowercase())
+        })
+    }
+
+    pub fn get(&self, id: u64) -> Option<&RecordedMessage> {
+        self.messages.iter().find(|m| m.id == id)
+    }
+
+    pub fn clear(&mut self) {
+        self.messages.clear();
+        self.selected = None;
+    }
+}
+
+/// Builds a small indented tree of the decoded `graph` struct, used by the
+/// inspector detail view.
+pub fn format_graph(graph: &quirky_binder_capnp::graph::Reader) -> capnp::Result<String> {
+    let mut out = String::new();
+    writeln!(&mut out, "graph").ok();
+
+    writeln!(&mut out, "  nodes").ok();
+    for node in graph.get_nodes()? {
+        writeln!(&mut out, "    - name: {}", node.get_name()?.to_str()?).ok();
+    }
+
+    writeln!(&mut out, "  edges").ok();
+    for edge in graph.get_edges()? {
+        writeln!(
+            &mut out,
+            "    - {}[{}] -> {}[{}]",
+            edge.get_tail_name()?.to_str()?,
+            edge.get_tail_index(),
+            edge.get_head_name()?.to_str()?,
+            edge.get_head_index(),
+        )
+        .ok();
+    }
+
+    Ok(out)
+}
+
+/// Builds a small indented tree of the decoded `node_statuses` list, used by
+/// the inspector detail view.
+pub fn format_statuses(
+    statuses: &capnp::struct_list::Reader<quirky_binder_capnp::node_status::Owned>,
+) -> capnp::Result<String> {
+    let mut out = String::new();
+    writeln!(&mut out, "node_statuses").ok();
+
+    for status in statuses.iter() {
+        writeln!(&mut out, "  - node: {}", status.get_node_name()?.to_str()?).ok();
+        write!(&mut out, "    input_read: [").ok();
+        for (i, v) in status.get_input_read()?.iter().enumerate() {
+            if i > 0 {
+                write!(&mut out, ", ").ok();
+            }
+            write!(&mut out, "{v}").ok();
+        }
+        writeln!(&mut out, "]").ok();
+        write!(&mut out, "    output_written: [").ok();
+        for (i, v) in status.get_output_written()?.iter().enumerate() {
+            if i > 0 {
+                write!(&mut out, ", ").ok();
+            }
+            write!(&mut out, "{v}").ok();
+        }
+        writeln!(&mut out, "]").ok();
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(name: &'static str) -> InspectorEvent {
+        InspectorEvent {
+            direction: Direction::Sent,
+            name,
+            byte_size: 0,
+            tree: String::new(),
+        }
+    }
+
+    #[test]
+    fn push_is_ignored_while_frozen() {
+        let mut inspector = Inspector::default();
+        inspector.frozen = true;
+        inspector.push(event("graph_request"));
+        assert_eq!(inspector.iter().count(), 0);
+    }
+
+    #[test]
+    fn push_evicts_oldest_once_full() {
+        let mut inspector = Inspector::default();
+        for _ in 0..MAX_MESSAGES + 1 {
+            inspector.push(event("graph_request"));
+        }
+        assert_eq!(inspector.iter().count(), MAX_MESSAGES);
+    }
+
+    #[test]
+    fn iter_filters_case_insensitively_by_name() {
+        let mut inspector = Inspector::default();
+        inspector.push(event("graph_request"));
+        inspector.push(event("node_statuses_request"));
+
+        inspector.filter = "GRAPH".to_string();
+        let names: Vec<_> = inspector.iter().map(|m| m.name).collect();
+        assert_eq!(names, vec!["graph_request"]);
+    }
+
+    #[test]
+    fn clear_resets_messages_and_selection() {
+        let mut inspector = Inspector::default();
+        inspector.push(event("graph_request"));
+        inspector.selected = inspector.iter().next().map(|m| m.id);
+        assert!(inspector.selected.is_some());
+
+        inspector.clear();
+        assert_eq!(inspector.iter().count(), 0);
+        assert_eq!(inspector.selected, None);
+    }
+
+    #[test]
+    fn selection_survives_eviction_of_older_messages() {
+        let mut inspector = Inspector::default();
+        inspector.push(event("graph_request"));
+        let selected_id = inspector.iter().next().unwrap().id;
+        inspector.selected = Some(selected_id);
+
+        for _ in 0..MAX_MESSAGES {
+            inspector.push(event("node_statuses_request"));
+        }
+
+        // The originally selected message has been evicted, so it's no
+        // longer found...
+        assert!(inspector.get(selected_id).is_none());
+        // ...but `selected` still refers to it by id rather than silently
+        // pointing at whatever now occupies its old position.
+        assert_eq!(inspector.selected, Some(selected_id));
+    }
+
+    #[test]
+    fn get_looks_up_by_stable_id_not_position() {
+        let mut inspector = Inspector::default();
+        inspector.push(event("a"));
+        inspector.push(event("b"));
+        let ids: Vec<_> = inspector.iter().map(|m| m.id).collect();
+
+        inspector.push(event("c"));
+        for id in ids {
+            assert!(inspector.get(id).is_some());
+        }
+    }
+}
@@ -0,0 +1,568 @@
+//! Pure-Rust replacement for shelling out to Graphviz `dot`. Lays the
+//! pipeline graph out with a classic Sugiyama layered-layout pipeline (cycle
+//! breaking, longest-path layering, median-heuristic crossing reduction,
+//! barycenter x-coordinates) and renders the result directly as SVG, so the
+//! tool has no external process dependency and can run in a WASM build.
+
+use std::collections::{BTreeSet, HashMap};
+
+pub struct EdgeSpec {
+    pub tail: String,
+    pub tail_index: u32,
+    pub head: String,
+    pub head_index: u32,
+    pub tail_label: Option<String>,
+    pub head_label: Option<String>,
+    /// Items/sec throughput along this edge, used to color it relative to
+    /// the rest of the graph; `None` until a second sample lets a rate be
+    /// computed.
+    pub rate: Option<f64>,
+}
+
+const NODE_HEIGHT: f64 = 32.0;
+const NODE_H_PADDING: f64 = 16.0;
+const CHAR_WIDTH: f64 = 7.2;
+const DUMMY_WIDTH: f64 = 1.0;
+const LAYER_SPACING: f64 = 90.0;
+const NODE_SPACING: f64 = 40.0;
+const MARGIN: f64 = 20.0;
+const MEDIAN_PASSES: usize = 4;
+const ALIGN_PASSES: usize = 4;
+
+fn node_width(name: &str) -> f64 {
+    name.chars().count() as f64 * CHAR_WIDTH + NODE_H_PADDING * 2.0
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Picks a stroke color and width for an edge relative to the busiest edge
+/// in the graph: idle or not-yet-measured edges are dimmed, hot ones are
+/// highlighted and drawn thicker.
+fn edge_style(rate: Option<f64>, max_rate: f64) -> (&'static str, f64) {
+    let Some(rate) = rate else {
+        return ("#ccc", 1.5);
+    };
+    if max_rate <= 0.0 {
+        return ("#888", 1.5);
+    }
+    let t = (rate / max_rate).clamp(0.0, 1.0);
+    if t < 0.25 {
+        ("#bbb", 1.5)
+    } else if t < 0.5 {
+        ("#888", 2.0)
+    } else if t < 0.75 {
+        ("#e07b39", 2.5)
+    } else {
+        ("#d62728", 3.0)
+    }
+}
+
+/// (1) Break cycles by DFS, temporarily reversing back-edges.
+///
+/// Returns the set of `(tail, head)` pairs (node indices) that DFS found to
+/// be a back-edge; the acyclic graph used for layering treats those edges as
+/// `(head, tail)` instead.
+fn find_back_edges(
+    node_count: usize,
+    edges: &BTreeSet<(usize, usize)>,
+) -> BTreeSet<(usize, usize)> {
+    let mut out_adj = vec![Vec::new(); node_count];
+    for &(t, h) in edges {
+        out_adj[t].push(h);
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum State {
+        Unvisited,
+        OnStack,
+        Done,
+    }
+
+    let mut state = vec![State::Unvisited; node_count];
+    let mut back_edges = BTreeSet::new();
+
+    fn visit(
+        v: usize,
+        out_adj: &[Vec<usize>],
+        state: &mut [State],
+        back_edges: &mut BTreeSet<(usize, usize)>,
+    ) {
+        state[v] = State::OnStack;
+        for &w in &out_adj[v] {
+            match state[w] {
+                State::OnStack => {
+                    back_edges.insert((v, w));
+                }
+                State::Unvisited => visit(w, out_adj, state, back_edges),
+                State::Done => {}
+            }
+        }
+        state[v] = State::Done;
+    }
+
+    for v in 0..node_count {
+        if state[v] == State::Unvisited {
+            visit(v, &out_adj, &mut state, &mut back_edges);
+        }
+    }
+
+    back_edges
+}
+
+/// (2) Assign each node a layer via longest-path from sources:
+/// `layer(v) = max over incoming edges of layer(u) + 1`.
+fn assign_layers(node_count: usize, acyclic_edges: &BTreeSet<(usize, usize)>) -> Vec<usize> {
+    let mut out_adj = vec![Vec::new(); node_count];
+    let mut in_degree = vec![0usize; node_count];
+    for &(t, h) in acyclic_edges {
+        out_adj[t].push(h);
+        in_degree[h] += 1;
+    }
+
+    let mut layer = vec![0usize; node_count];
+    let mut queue: Vec<usize> = (0..node_count).filter(|&v| in_degree[v] == 0).collect();
+    let mut head = 0;
+    let mut remaining_in_degree = in_degree.clone();
+
+    while head < queue.len() {
+        let v = queue[head];
+        head += 1;
+        for &w in &out_adj[v] {
+            layer[w] = layer[w].max(layer[v] + 1);
+            remaining_in_degree[w] -= 1;
+            if remaining_in_degree[w] == 0 {
+                queue.push(w);
+            }
+        }
+    }
+
+    layer
+}
+
+struct Chain {
+    /// Vertex ids from tail to head (endpoints included); intermediate ones
+    /// are dummy vertices inserted so that every hop connects adjacent
+    /// layers.
+    vertices: Vec<usize>,
+}
+
+/// The screen-space box of a rendered node, in the same coordinate system as
+/// the SVG document, so callers can hit-test pointer clicks against it.
+pub struct NodeBox {
+    pub name: String,
+    pub center_x: f64,
+    pub center_y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Lays out `nodes` (by name) and `edges` between them, returning a
+/// self-contained SVG document string plus each node's hit-test box.
+pub fn layout_to_svg(nodes: &[String], edges: &[EdgeSpec]) -> (String, Vec<NodeBox>) {
+    let node_count = nodes.len();
+    let mut index_of: HashMap<&str, usize> = HashMap::new();
+    for (i, name) in nodes.iter().enumerate() {
+        index_of.insert(name.as_str(), i);
+    }
+
+    // Node-pair edges, deduplicated: cycle-breaking and layering are
+    // node-level concepts, so multiple ports between the same two nodes
+    // don't change either.
+    let mut node_pair_edges: BTreeSet<(usize, usize)> = BTreeSet::new();
+    // Every distinct edge *identity* (node pair plus port indices),
+    // deduplicating only truly-identical edges, so that two edges between
+    // the same pair of nodes on different ports each keep their own routed
+    // chain and label instead of collapsing onto one.
+    let mut edge_keys: BTreeSet<(usize, u32, usize, u32)> = BTreeSet::new();
+    for edge in edges {
+        if let (Some(&t), Some(&h)) = (
+            index_of.get(edge.tail.as_str()),
+            index_of.get(edge.head.as_str()),
+        ) {
+            if t != h {
+                node_pair_edges.insert((t, h));
+                edge_keys.insert((t, edge.tail_index, h, edge.head_index));
+            }
+        }
+    }
+
+    let back_edges = find_back_edges(node_count, &node_pair_edges);
+    let acyclic_edges: BTreeSet<(usize, usize)> = node_pair_edges
+        .iter()
+        .map(|&(t, h)| {
+            if back_edges.contains(&(t, h)) {
+                (h, t)
+            } else {
+                (t, h)
+            }
+        })
+        .collect();
+
+    let layer = assign_layers(node_count, &acyclic_edges);
+
+    // (3) Insert dummy nodes on edges that span more than one layer so every
+    // edge connects adjacent layers. Each edge identity gets its own chain
+    // of dummy vertices, even when it shares a node pair with another edge,
+    // so parallel edges can be routed and labelled independently.
+    let mut vertex_layer: Vec<usize> = layer.clone();
+    let mut vertex_width: Vec<f64> = nodes.iter().map(|n| node_width(n)).collect();
+    let mut chains: HashMap<(usize, u32, usize, u32), Chain> = HashMap::new();
+    let mut up_neighbors: Vec<Vec<usize>> = vec![Vec::new(); node_count];
+    let mut down_neighbors: Vec<Vec<usize>> = vec![Vec::new(); node_count];
+
+    for &(tail, tail_index, head, head_index) in &edge_keys {
+        let tail_layer = layer[tail];
+        let head_layer = layer[head];
+        let step: i64 = if head_layer >= tail_layer { 1 } else { -1 };
+        let span = (head_layer as i64 - tail_layer as i64).unsigned_abs() as usize;
+
+        let mut chain_vertices = vec![tail];
+        let mut current_layer = tail_layer as i64;
+        for _ in 1..span {
+            current_layer += step;
+            let dummy_id = vertex_layer.len();
+            vertex_layer.push(current_layer as usize);
+            vertex_width.push(DUMMY_WIDTH);
+            up_neighbors.push(Vec::new());
+            down_neighbors.push(Vec::new());
+            chain_vertices.push(dummy_id);
+        }
+        chain_vertices.push(head);
+
+        for pair in chain_vertices.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let (upper, lower) = if vertex_layer[a] <= vertex_layer[b] {
+                (a, b)
+            } else {
+                (b, a)
+            };
+            down_neighbors[upper].push(lower);
+            up_neighbors[lower].push(upper);
+        }
+
+        chains.insert(
+            (tail, tail_index, head, head_index),
+            Chain {
+                vertices: chain_vertices,
+            },
+        );
+    }
+
+    let vertex_count = vertex_layer.len();
+    let max_layer = vertex_layer.iter().copied().max().unwrap_or(0);
+
+    // (4) Order nodes within each layer to reduce crossings using the
+    // iterated median heuristic.
+    let mut layers: Vec<Vec<usize>> = vec![Vec::new(); max_layer + 1];
+    for v in 0..vertex_count {
+        layers[vertex_layer[v]].push(v);
+    }
+
+    let mut order_index = vec![0usize; vertex_count];
+    for layer_vertices in &layers {
+        for (i, &v) in layer_vertices.iter().enumerate() {
+            order_index[v] = i;
+        }
+    }
+
+    let median = |neighbors: &[usize], order_index: &[usize]| -> Option<f64> {
+        if neighbors.is_empty() {
+            return None;
+        }
+        let mut positions: Vec<usize> = neighbors.iter().map(|&n| order_index[n]).collect();
+        positions.sort_unstable();
+        let mid = positions.len() / 2;
+        Some(if positions.len() % 2 == 1 {
+            positions[mid] as f64
+        } else if positions.len() == 2 {
+            (positions[0] + positions[1]) as f64 / 2.0
+        } else {
+            (positions[mid - 1] + positions[mid]) as f64 / 2.0
+        })
+    };
+
+    for pass in 0..MEDIAN_PASSES {
+        let sweep_down = pass % 2 == 0;
+        let layer_range: Vec<usize> = if sweep_down {
+            (1..=max_layer).collect()
+        } else {
+            (0..max_layer).rev().collect()
+        };
+
+        for l in layer_range {
+            let neighbors_of = if sweep_down {
+                &up_neighbors
+            } else {
+                &down_neighbors
+            };
+            let mut with_medians: Vec<(usize, f64)> = layers[l]
+                .iter()
+                .enumerate()
+                .map(|(i, &v)| {
+                    (
+                        v,
+                        median(&neighbors_of[v], &order_index).unwrap_or(i as f64),
+                    )
+                })
+                .collect();
+            with_medians.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+            layers[l] = with_medians.into_iter().map(|(v, _)| v).collect();
+            for (i, &v) in layers[l].iter().enumerate() {
+                order_index[v] = i;
+            }
+        }
+    }
+
+    // (5) Assign x-coordinates (barycenter alignment) and y = layer * spacing.
+    let mut x = vec![0.0f64; vertex_count];
+    for layer_vertices in &layers {
+        let mut cursor = MARGIN;
+        for &v in layer_vertices {
+            x[v] = cursor + vertex_width[v] / 2.0;
+            cursor += vertex_width[v] + NODE_SPACING;
+        }
+    }
+
+    for _ in 0..ALIGN_PASSES {
+        let mut desired = x.clone();
+        for v in 0..vertex_count {
+            let mut sum = 0.0;
+            let mut count = 0usize;
+            for &u in up_neighbors[v].iter().chain(down_neighbors[v].iter()) {
+                sum += x[u];
+                count += 1;
+            }
+            if count > 0 {
+                desired[v] = sum / count as f64;
+            }
+        }
+
+        for layer_vertices in &layers {
+            let mut ordered: Vec<usize> = layer_vertices.clone();
+            ordered.sort_by(|&a, &b| order_index[a].cmp(&order_index[b]));
+            let mut prev_right = MARGIN;
+            for &v in &ordered {
+                let half = vertex_width[v] / 2.0;
+                let min_x = prev_right + half;
+                x[v] = desired[v].max(min_x);
+                prev_right = x[v] + half + NODE_SPACING;
+            }
+        }
+    }
+
+    let y: Vec<f64> = vertex_layer
+        .iter()
+        .map(|&l| MARGIN + l as f64 * LAYER_SPACING)
+        .collect();
+
+    let node_boxes: Vec<NodeBox> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, name)| NodeBox {
+            name: name.clone(),
+            center_x: x[i],
+            center_y: y[i] + NODE_HEIGHT / 2.0,
+            width: vertex_width[i],
+            height: NODE_HEIGHT,
+        })
+        .collect();
+
+    let svg = render_svg(nodes, edges, &chains, &x, &y, &vertex_width);
+    (svg, node_boxes)
+}
+
+/// Horizontal gap between two parallel edges' drawn routes so same-node-pair
+/// edges with different ports don't overlap.
+const PARALLEL_EDGE_OFFSET: f64 = 10.0;
+
+fn render_svg(
+    nodes: &[String],
+    edges: &[EdgeSpec],
+    chains: &HashMap<(usize, u32, usize, u32), Chain>,
+    x: &[f64],
+    y: &[f64],
+    vertex_width: &[f64],
+) -> String {
+    let width = x
+        .iter()
+        .zip(vertex_width)
+        .map(|(&cx, &w)| cx + w / 2.0 + MARGIN)
+        .fold(0.0_f64, f64::max);
+    let height = y.iter().copied().fold(0.0_f64, f64::max) + NODE_HEIGHT + MARGIN;
+
+    let max_rate = edges.iter().filter_map(|e| e.rate).fold(0.0_f64, f64::max);
+
+    let mut svg = String::new();
+    svg.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\">\n"
+    ));
+    svg.push_str(
+        "<style>\
+         text{font-family:sans-serif;font-size:13px;}\
+         .edge{fill:none;stroke:#555;stroke-width:1.5;}\
+         .edge-label{font-size:10px;fill:#555;}\
+         .node{fill:#eef;stroke:#335;stroke-width:1.5;}\
+         </style>\n",
+    );
+
+    let index_of = |name: &str| nodes.iter().position(|n| n == name);
+
+    // How many edges share each (tail, head) node pair, so parallel edges
+    // can be fanned out by an offset instead of overlapping.
+    let mut pair_counts: HashMap<(usize, usize), usize> = HashMap::new();
+    for edge in edges {
+        if let (Some(tail), Some(head)) = (index_of(&edge.tail), index_of(&edge.head)) {
+            *pair_counts.entry((tail, head)).or_insert(0) += 1;
+        }
+    }
+    let mut pair_seen: HashMap<(usize, usize), usize> = HashMap::new();
+
+    for edge in edges {
+        if let (Some(tail), Some(head)) = (index_of(&edge.tail), index_of(&edge.head)) {
+            if let Some(chain) = chains.get(&(tail, edge.tail_index, head, edge.head_index)) {
+                let count = pair_counts.get(&(tail, head)).copied().unwrap_or(1);
+                let seen = pair_seen.entry((tail, head)).or_insert(0);
+                let ordinal = *seen;
+                *seen += 1;
+                let offset = (ordinal as f64 - (count as f64 - 1.0) / 2.0) * PARALLEL_EDGE_OFFSET;
+
+                let points: String = chain
+                    .vertices
+                    .iter()
+                    .map(|&v| format!("{},{}", x[v] + offset, y[v] + NODE_HEIGHT / 2.0))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let (color, stroke_width) = edge_style(edge.rate, max_rate);
+                svg.push_str(&format!(
+                    "<polyline class=\"edge\" style=\"stroke:{color};stroke-width:{stroke_width}\" points=\"{points}\" />\n"
+                ));
+
+                if let Some(tail_label) = &edge.tail_label {
+                    svg.push_str(&format!(
+                        "<text class=\"edge-label\" x=\"{}\" y=\"{}\">{}</text>\n",
+                        x[tail] + offset + vertex_width[tail] / 2.0 + 4.0,
+                        y[tail] + NODE_HEIGHT / 2.0 - 4.0,
+                        escape_xml(tail_label)
+                    ));
+                }
+                if let Some(head_label) = &edge.head_label {
+                    svg.push_str(&format!(
+                        "<text class=\"edge-label\" x=\"{}\" y=\"{}\">{}</text>\n",
+                        x[head] + offset - vertex_width[head] / 2.0 - 4.0,
+                        y[head] + NODE_HEIGHT / 2.0 + 12.0,
+                        escape_xml(head_label)
+                    ));
+                }
+            }
+        }
+    }
+
+    for (i, name) in nodes.iter().enumerate() {
+        svg.push_str(&format!(
+            "<rect class=\"node\" x=\"{}\" y=\"{}\" width=\"{}\" height=\"{NODE_HEIGHT}\" rx=\"4\" />\n",
+            x[i] - vertex_width[i] / 2.0,
+            y[i],
+            vertex_width[i],
+        ));
+        svg.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" dominant-baseline=\"middle\">{}</text>\n",
+            x[i],
+            y[i] + NODE_HEIGHT / 2.0,
+            escape_xml(name)
+        ));
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge(tail: &str, tail_index: u32, head: &str, head_index: u32) -> EdgeSpec {
+        EdgeSpec {
+            tail: tail.to_owned(),
+            tail_index,
+            head: head.to_owned(),
+            head_index,
+            tail_label: None,
+            head_label: None,
+            rate: None,
+        }
+    }
+
+    #[test]
+    fn find_back_edges_detects_cycle() {
+        // 0 -> 1 -> 2 -> 0
+        let edges: BTreeSet<(usize, usize)> = [(0, 1), (1, 2), (2, 0)].into_iter().collect();
+        let back_edges = find_back_edges(3, &edges);
+        assert_eq!(back_edges.len(), 1);
+        assert!(back_edges.contains(&(2, 0)));
+    }
+
+    #[test]
+    fn find_back_edges_empty_on_dag() {
+        let edges: BTreeSet<(usize, usize)> = [(0, 1), (1, 2)].into_iter().collect();
+        assert!(find_back_edges(3, &edges).is_empty());
+    }
+
+    #[test]
+    fn assign_layers_longest_path() {
+        // 0 -> 1 -> 2 and 0 -> 2, so 2 should be placed after 1, not right
+        // after 0.
+        let edges: BTreeSet<(usize, usize)> = [(0, 1), (1, 2), (0, 2)].into_iter().collect();
+        let layer = assign_layers(3, &edges);
+        assert_eq!(layer, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn parallel_edges_get_distinct_chains_and_labels() {
+        let nodes = vec!["a".to_owned(), "b".to_owned()];
+        let edges = vec![
+            {
+                let mut e = edge("a", 0, "b", 0);
+                e.tail_label = Some("1".to_owned());
+                e
+            },
+            {
+                let mut e = edge("a", 1, "b", 1);
+                e.tail_label = Some("2".to_owned());
+                e
+            },
+        ];
+
+        let (svg, node_boxes) = layout_to_svg(&nodes, &edges);
+
+        assert_eq!(node_boxes.len(), 2);
+        assert_eq!(
+            svg.matches("<polyline").count(),
+            2,
+            "each parallel edge should draw its own line"
+        );
+        assert_eq!(svg.matches("<text class=\"edge-label\"").count(), 2);
+        assert!(svg.contains(">1<"));
+        assert!(svg.contains(">2<"));
+    }
+
+    #[test]
+    fn layout_handles_cyclic_graph_without_panicking() {
+        let nodes = vec!["a".to_owned(), "b".to_owned(), "c".to_owned()];
+        let edges = vec![
+            edge("a", 0, "b", 0),
+            edge("b", 0, "c", 0),
+            edge("c", 0, "a", 0),
+        ];
+
+        let (svg, node_boxes) = layout_to_svg(&nodes, &edges);
+
+        assert_eq!(node_boxes.len(), 3);
+        assert_eq!(svg.matches("<polyline").count(), 3);
+    }
+}
@@ -1,197 +1,356 @@
 use std::{
     collections::BTreeMap,
     env::args,
-    fmt::Write,
-    sync::mpsc::{sync_channel, Receiver, SyncSender, TryRecvError},
+    sync::{
+        mpsc::{sync_channel, Receiver, SyncSender, TryRecvError},
+        Arc, Mutex,
+    },
     thread::JoinHandle,
     time::{Duration, Instant},
 };
 
 use eframe::{
     egui,
-    egui::{Align, Layout, TextWrapMode, ViewportCommand},
+    egui::{Align, Layout, TextWrapMode},
     glow,
 };
-use futures::{task::LocalSpawnExt, AsyncReadExt, AsyncWriteExt, FutureExt};
+use egui_dock::{DockArea, DockState, NodeIndex, Style, TabViewer};
+use futures::{future::Future, task::LocalSpawnExt, AsyncReadExt, FutureExt};
 use quirky_binder_capnp::quirky_binder_capnp;
 use resvg::tiny_skia;
-use smol::{
-    process::{Command, Stdio},
-    Timer,
-};
+use smol::Timer;
 use teleop::{
     attach::unix_socket::connect, cancellation::CancellationToken,
     operate::capnp::client_connection,
 };
 use usvg::Tree;
 
-const RUST_SVG: &str = include_str!("rust.svg");
-
-pub fn node_name_to_dot_id(name: &str) -> String {
-    format!("\"{name}\"")
-}
-
-pub async fn dot_to_svg(dot_source: &str) -> std::io::Result<String> {
-    let mut child = Command::new("dot")
-        .arg("-Tsvg")
-        .stdin(Stdio::piped())
-        .stdout(Stdio::piped())
-        .spawn()?;
+use config::SharedPollerConfig;
+use graph_state::{ConnectionStatus, EdgeInfo, GraphUpdate, NodeStatus};
+use inspector::{Direction, Inspector, InspectorEvent};
+use layout::{EdgeSpec, NodeBox};
+use rates::RateTracker;
 
-    if let Some(mut stdin) = child.stdin.take() {
-        stdin.write_all(dot_source.as_bytes()).await?;
-    }
+mod config;
+mod graph_state;
+mod inspector;
+mod layout;
+mod rates;
 
-    let output = child.output().await?;
+const RUST_SVG: &str = include_str!("rust.svg");
 
-    if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
-    } else {
-        let error_message = String::from_utf8_lossy(&output.stderr);
-        Err(std::io::Error::other(format!(
-            "Erreur lors de l'ex√©cution de la commande dot : {error_message}"
-        )))
+/// Races `fut` against a `request_timeout` timer so a wedged server surfaces
+/// an error instead of hanging the poller forever.
+async fn with_timeout<T>(
+    fut: impl Future<Output = capnp::Result<T>>,
+    request_timeout: Duration,
+) -> Result<T, Box<dyn std::error::Error>> {
+    let mut fut = Box::pin(fut.fuse());
+    let mut timer = Box::pin(Timer::after(request_timeout).fuse());
+    futures::select! {
+        res = fut => Ok(res?),
+        () = timer => Err("RPC request timed out".into()),
     }
 }
 
 pub fn state_poller(
-    sender: SyncSender<String>,
+    pid: u32,
+    sender: SyncSender<GraphUpdate>,
+    inspector_sender: SyncSender<InspectorEvent>,
+    status_sender: SyncSender<ConnectionStatus>,
+    config: SharedPollerConfig,
     ctx: egui::Context,
     cancellation_token: CancellationToken,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let mut args = args();
-    args.next();
-    let pid: u32 = args
-        .next()
-        .unwrap_or_else(|| "PID missing".to_owned())
-        .parse()?;
-
     let mut exec = futures::executor::LocalPool::new();
     let spawn = exec.spawner();
 
     exec.run_until(async move {
-        let stream = connect(pid).await?;
-        let (input, output) = stream.split();
-        let (rpc_system, teleop) = client_connection(input, output).await;
-        let rpc_disconnect = rpc_system.get_disconnector();
-
-        spawn.spawn_local(async {
-            if let Err(e) = rpc_system.await {
-                eprintln!("Connection interrupted {e}");
-            }
-        })?;
+        let initial_backoff = |config: &SharedPollerConfig| {
+            let config = config.lock().unwrap();
+            config
+                .reconnect_initial_backoff()
+                .min(config.reconnect_max_backoff())
+        };
+        let mut backoff = initial_backoff(&config);
 
-        let mut req = teleop.service_request();
-        req.get().set_name("state");
-        let state = req.send().promise.await?;
-        let state = state.get()?.get_service();
-        let state: quirky_binder_capnp::state::Client = state.get_as()?;
+        loop {
+            status_sender.send(ConnectionStatus::Connecting).ok();
+            ctx.request_repaint();
 
-        let graph = state.graph_request().send().promise.await?;
-        let graph = graph.get()?.get_graph()?;
+            let mut reached_connected = false;
+            match run_session(
+                pid,
+                &sender,
+                &inspector_sender,
+                &status_sender,
+                &config,
+                &ctx,
+                &spawn,
+                &cancellation_token,
+                &mut reached_connected,
+            )
+            .await
+            {
+                Ok(()) => break,
+                Err(err) => {
+                    eprintln!("[pid {pid}] connection lost: {err}");
+                    status_sender.send(ConnectionStatus::Reconnecting).ok();
+                    ctx.request_repaint();
+
+                    // A session that made it to `Connected` before dying was
+                    // a real, working connection, not a wedged retry storm,
+                    // so the next attempt shouldn't inherit a long backoff
+                    // built up by earlier failures.
+                    if reached_connected {
+                        backoff = initial_backoff(&config);
+                    }
 
-        let update_graph = async || -> Result<(), Box<dyn std::error::Error>> {
-            let statuses = state.node_statuses_request().send().promise.await?;
-            let statuses = statuses.get()?.get_statuses()?;
-            let statuses = statuses
-                .into_iter()
-                .map(|s| Ok((s.get_node_name()?.to_str()?, s)))
-                .collect::<capnp::Result<BTreeMap<&str, _>>>()?;
+                    let mut wait = Box::pin(Timer::after(backoff).fuse());
+                    let mut cancelled = cancellation_token.cancelled().fuse();
+                    futures::select! {
+                        () = wait => {}
+                        () = cancelled => break,
+                    }
 
-            let mut dot = String::new();
+                    let max_backoff = config.lock().unwrap().reconnect_max_backoff();
+                    backoff = (backoff * 2).min(max_backoff);
+                }
+            }
+        }
 
-            writeln!(&mut dot, "digraph G {{")?;
+        Ok::<_, Box<dyn std::error::Error>>(())
+    })?;
 
-            let nodes = graph.get_nodes()?;
+    exec.run();
 
-            for node in nodes {
-                writeln!(
-                    &mut dot,
-                    "{}",
-                    node_name_to_dot_id(node.get_name()?.to_str()?)
-                )?;
-            }
+    status_sender.send(ConnectionStatus::Disconnected).ok();
+    ctx.request_repaint();
 
-            let edges = graph.get_edges()?;
+    Ok(())
+}
+
+/// Runs a single connection attempt through to completion: connects,
+/// streams graph updates until cancelled, then tears the RPC system down.
+/// Returns `Ok(())` only on a clean, cancellation-driven shutdown; any other
+/// failure (including a request timing out) is handed back to
+/// `state_poller`'s reconnect loop.
+#[allow(clippy::too_many_arguments)]
+async fn run_session(
+    pid: u32,
+    sender: &SyncSender<GraphUpdate>,
+    inspector_sender: &SyncSender<InspectorEvent>,
+    status_sender: &SyncSender<ConnectionStatus>,
+    config: &SharedPollerConfig,
+    ctx: &egui::Context,
+    spawn: &futures::executor::LocalSpawner,
+    cancellation_token: &CancellationToken,
+    reached_connected: &mut bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let stream = connect(pid).await?;
+    let (input, output) = stream.split();
+    let (rpc_system, teleop) = client_connection(input, output).await;
+    let rpc_disconnect = rpc_system.get_disconnector();
+
+    spawn.spawn_local(async {
+        if let Err(e) = rpc_system.await {
+            eprintln!("Connection interrupted {e}");
+        }
+    })?;
 
-            for edge in edges {
+    let request_timeout = config.lock().unwrap().request_timeout();
+
+    let mut req = teleop.service_request();
+    req.get().set_name("state");
+    inspector_sender
+        .send(InspectorEvent {
+            direction: Direction::Sent,
+            name: "service",
+            byte_size: "state".len(),
+            tree: "service\n  name: \"state\"".to_owned(),
+        })
+        .ok();
+    let state = with_timeout(req.send().promise, request_timeout).await?;
+    let state = state.get()?;
+    inspector_sender
+        .send(InspectorEvent {
+            direction: Direction::Received,
+            name: "service",
+            byte_size: state.total_size()?.word_count as usize * 8,
+            tree: "service".to_owned(),
+        })
+        .ok();
+    let state = state.get_service();
+    let state: quirky_binder_capnp::state::Client = state.get_as()?;
+
+    inspector_sender
+        .send(InspectorEvent {
+            direction: Direction::Sent,
+            name: "graph",
+            byte_size: 0,
+            tree: "graph".to_owned(),
+        })
+        .ok();
+    let graph = with_timeout(state.graph_request().send().promise, request_timeout).await?;
+    let graph = graph.get()?.get_graph()?;
+    {
+        let tree = inspector::format_graph(&graph)?;
+        inspector_sender
+            .send(InspectorEvent {
+                direction: Direction::Received,
+                name: "graph",
+                byte_size: graph.total_size()?.word_count as usize * 8,
+                tree,
+            })
+            .ok();
+    }
+
+    status_sender.send(ConnectionStatus::Connected).ok();
+    ctx.request_repaint();
+    *reached_connected = true;
+
+    let mut rate_tracker = RateTracker::default();
+
+    let update_graph = async || -> Result<(), Box<dyn std::error::Error>> {
+        let request_timeout = config.lock().unwrap().request_timeout();
+
+        inspector_sender
+            .send(InspectorEvent {
+                direction: Direction::Sent,
+                name: "node_statuses",
+                byte_size: 0,
+                tree: "node_statuses".to_owned(),
+            })
+            .ok();
+        let statuses = with_timeout(
+            state.node_statuses_request().send().promise,
+            request_timeout,
+        )
+        .await?;
+        let statuses = statuses.get()?.get_statuses()?;
+        inspector_sender
+            .send(InspectorEvent {
+                direction: Direction::Received,
+                name: "node_statuses",
+                byte_size: statuses.total_size()?.word_count as usize * 8,
+                tree: inspector::format_statuses(&statuses)?,
+            })
+            .ok();
+        let statuses = statuses
+            .into_iter()
+            .map(|s| Ok((s.get_node_name()?.to_str()?, s)))
+            .collect::<capnp::Result<BTreeMap<&str, _>>>()?;
+
+        let node_names: Vec<String> = graph
+            .get_nodes()?
+            .into_iter()
+            .map(|node| Ok(node.get_name()?.to_str()?.to_owned()))
+            .collect::<capnp::Result<_>>()?;
+
+        let now = Instant::now();
+
+        let edge_specs: Vec<EdgeSpec> = graph
+            .get_edges()?
+            .into_iter()
+            .map(|edge| {
                 let tail_name = edge.get_tail_name()?.to_str()?;
                 let head_name = edge.get_head_name()?.to_str()?;
 
-                write!(
-                    &mut dot,
-                    "{} -> {} [",
-                    node_name_to_dot_id(tail_name),
-                    node_name_to_dot_id(head_name)
-                )?;
-
                 let tail_index = edge.get_tail_index();
-                let tail_counter = statuses
+                let tail_value = statuses
                     .get(tail_name)
                     .map(|s| capnp::Result::Ok(s.get_output_written()?.get(tail_index as _)))
                     .transpose()?;
+                let tail_rate = tail_value
+                    .and_then(|v| rate_tracker.output_rate(tail_name, tail_index, v, now));
+                let tail_label = tail_value.map(|n| rates::format_count(n, tail_rate));
 
                 let head_index = edge.get_head_index();
-                let head_counter = statuses
+                let head_value = statuses
                     .get(head_name)
                     .map(|s| capnp::Result::Ok(s.get_input_read()?.get(head_index as _)))
                     .transpose()?;
-
-                for (i, (attr, val)) in tail_counter
-                    .map(|n| ("taillabel", n.to_string()))
-                    .into_iter()
-                    .chain(
-                        head_counter
-                            .map(|n| ("headlabel", n.to_string()))
-                            .into_iter(),
-                    )
-                    .enumerate()
-                {
-                    if i > 0 {
-                        write!(&mut dot, ", ")?;
-                    } else {
-                        writeln!(&mut dot)?;
-                    }
-                    writeln!(&mut dot, "{attr} = \"{val}\"",)?;
-                }
-
-                writeln!(&mut dot, "]")?;
+                let head_rate =
+                    head_value.and_then(|v| rate_tracker.input_rate(head_name, head_index, v, now));
+                let head_label = head_value.map(|n| rates::format_count(n, head_rate));
+
+                capnp::Result::Ok(EdgeSpec {
+                    tail: tail_name.to_owned(),
+                    tail_index,
+                    head: head_name.to_owned(),
+                    head_index,
+                    tail_label,
+                    head_label,
+                    rate: tail_rate.or(head_rate),
+                })
+            })
+            .collect::<capnp::Result<_>>()?;
+
+        let edge_infos: Vec<EdgeInfo> = edge_specs
+            .iter()
+            .map(|e| EdgeInfo {
+                tail: e.tail.clone(),
+                tail_index: e.tail_index,
+                head: e.head.clone(),
+                head_index: e.head_index,
+                rate: e.rate,
+            })
+            .collect();
+
+        let status_snapshot: BTreeMap<String, NodeStatus> = statuses
+            .iter()
+            .map(|(&name, s)| {
+                let input_read: Vec<u64> = s.get_input_read()?.iter().collect();
+                let output_written: Vec<u64> = s.get_output_written()?.iter().collect();
+                let total: u64 = input_read.iter().chain(output_written.iter()).sum();
+                rate_tracker.record_node_total(name, total, now);
+
+                capnp::Result::Ok((
+                    name.to_owned(),
+                    NodeStatus {
+                        input_read,
+                        output_written,
+                        rate_history: rate_tracker.node_history(name),
+                    },
+                ))
+            })
+            .collect::<capnp::Result<_>>()?;
+
+        let (svg, node_boxes) = layout::layout_to_svg(&node_names, &edge_specs);
+        sender
+            .send(GraphUpdate {
+                svg,
+                node_boxes,
+                statuses: status_snapshot,
+                edges: edge_infos,
+            })
+            .unwrap();
+
+        ctx.request_repaint();
+
+        let refresh_interval = config.lock().unwrap().refresh_interval();
+        Timer::after(refresh_interval).await;
+
+        Ok(())
+    };
+
+    loop {
+        let mut update = Box::pin(update_graph().fuse());
+        let mut cancelled = cancellation_token.cancelled().fuse();
+        futures::select! {
+            res = update => {
+                res?;
             }
-            writeln!(&mut dot, "}}")?;
-
-            //println!("DOT: {dot}");
-
-            let svg = dot_to_svg(&dot).await?;
-            sender.send(svg).unwrap();
-
-            ctx.request_repaint();
-
-            Timer::after(Duration::from_millis(3000)).await;
-
-            Ok(())
-        };
-
-        loop {
-            let mut update = Box::pin(update_graph().fuse());
-            let mut cancelled = cancellation_token.cancelled().fuse();
-            futures::select! {
-                res = update => {
-                    let () = res?;
-                }
-                () = cancelled => {
-                    break;
-                }
+            () = cancelled => {
+                break;
             }
         }
+    }
 
-        rpc_disconnect.await?;
+    rpc_disconnect.await?;
 
-        Timer::after(Duration::from_millis(3000)).await;
-
-        Ok::<_, Box<dyn std::error::Error>>(())
-    })?;
-
-    exec.run();
+    let shutdown_grace = config.lock().unwrap().shutdown_grace();
+    Timer::after(shutdown_grace).await;
 
     Ok(())
 }
@@ -201,151 +360,120 @@ enum Content {
     Dot(Tree),
 }
 
-struct SvgViewer {
+/// Each connection gets a graph tab and an inspector tab; a `Tab` carries
+/// its kind plus the index of the `Connection` it belongs to into
+/// `SvgViewer::connections`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tab {
+    Graph(usize),
+    Inspector(usize),
+}
+
+/// A single quirky_binder process being monitored: its own poller thread,
+/// channels and UI state, so a dead process only affects its own tabs.
+struct Connection {
+    pid: u32,
     content: Content,
+    graph_info: Option<GraphUpdate>,
+    selected_node: Option<String>,
+    status: ConnectionStatus,
     poller: Option<JoinHandle<Result<(), ()>>>,
-    receiver: Receiver<String>,
+    receiver: Receiver<GraphUpdate>,
+    inspector_receiver: Receiver<InspectorEvent>,
+    status_receiver: Receiver<ConnectionStatus>,
+    inspector: Inspector,
     cancellation_token: CancellationToken,
-    close_at: Option<Instant>,
 }
 
-impl SvgViewer {
-    fn new(cc: &eframe::CreationContext<'_>) -> Self {
-        cc.egui_ctx.set_pixels_per_point(1.5);
-
+impl Connection {
+    fn new(pid: u32, config: SharedPollerConfig, egui_ctx: &egui::Context) -> Self {
         let (sender, receiver) = sync_channel(1);
+        let (inspector_sender, inspector_receiver) = sync_channel(64);
+        let (status_sender, status_receiver) = sync_channel(8);
 
         let cancellation_token = CancellationToken::new();
 
         let poller = std::thread::spawn({
-            let ctx = cc.egui_ctx.clone();
+            let ctx = egui_ctx.clone();
             let cancellation_token = cancellation_token.clone();
             move || {
-                let res =
-                    state_poller(sender, ctx.clone(), cancellation_token.clone()).map_err(|err| {
-                        eprintln!("Error in poller: {err}");
-                    });
+                let res = state_poller(
+                    pid,
+                    sender,
+                    inspector_sender,
+                    status_sender,
+                    config,
+                    ctx.clone(),
+                    cancellation_token.clone(),
+                )
+                .map_err(|err| {
+                    eprintln!("[pid {pid}] Error in poller: {err}");
+                });
                 ctx.request_repaint();
                 res
             }
         });
 
         Self {
+            pid,
             content: Content::Logo(
                 usvg::Tree::from_data(RUST_SVG.as_bytes(), &usvg::Options::default())
                     .expect("parse rust.svg"),
             ),
+            graph_info: None,
+            selected_node: None,
+            status: ConnectionStatus::Connecting,
             poller: Some(poller),
             receiver,
+            inspector_receiver,
+            status_receiver,
+            inspector: Inspector::default(),
             cancellation_token,
-            close_at: None,
         }
     }
-}
-
-impl eframe::App for SvgViewer {
-    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
-        if let Some(at) = self.close_at {
-            let now = Instant::now();
-            if at < now {
-                ctx.send_viewport_cmd(ViewportCommand::Close);
-            } else {
-                ctx.request_repaint_after(at - now);
-            }
-        }
-
-        // https://github.com/emilk/egui/issues/5703
-        if frame.info().cpu_usage.is_none() {
-            return;
-        }
 
+    /// Drains this connection's channels; never closes the app even if this
+    /// connection has died, so the other tabs keep working.
+    fn poll(&mut self, ctx: &egui::Context) {
         match self.receiver.try_recv() {
-            Ok(svg) => {
+            Ok(update) => {
                 let mut options = usvg::Options::default();
                 options.fontdb_mut().load_system_fonts();
-                if let Ok(tree) = usvg::Tree::from_data(svg.as_bytes(), &options) {
+                if let Ok(tree) = usvg::Tree::from_data(update.svg.as_bytes(), &options) {
                     self.content = Content::Dot(tree);
+                    if self
+                        .selected_node
+                        .as_ref()
+                        .is_some_and(|name| !update.node_boxes.iter().any(|b| &b.name == name))
+                    {
+                        self.selected_node = None;
+                    }
+                    self.graph_info = Some(update);
                 }
                 ctx.request_repaint();
             }
             Err(TryRecvError::Empty) => {}
-            Err(TryRecvError::Disconnected) => {
-                let now = Instant::now();
-                let close_at = match self.close_at {
-                    Some(close_at) => close_at,
-                    None => {
-                        eprintln!("will close after 60s...");
-                        let at = now + Duration::from_secs(60);
-                        self.close_at = Some(at);
-                        at
-                    }
-                };
-                ctx.request_repaint_after(close_at - now);
+            Err(TryRecvError::Disconnected) => self.status = ConnectionStatus::Disconnected,
+        }
+
+        loop {
+            match self.inspector_receiver.try_recv() {
+                Ok(event) => self.inspector.push(event),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => break,
             }
         }
 
-        egui::CentralPanel::default().show(ctx, |ui| {
-            match &self.content {
-                Content::Logo(tree) => {
-                    let pixmap_size = tree.size().to_int_size();
-                    let available_size = ui.available_size();
-                    let zoom = (available_size.x / pixmap_size.width() as f32)
-                        .min(available_size.y / pixmap_size.height() as f32)
-                        * 0.75;
-                    let width = (pixmap_size.width() as f32 * zoom) as u32;
-                    let height = (pixmap_size.height() as f32 * zoom) as u32;
-
-                    if let Some(mut pixmap) = tiny_skia::Pixmap::new(width, height) {
-                        resvg::render(
-                            tree,
-                            tiny_skia::Transform::from_scale(zoom, zoom),
-                            &mut pixmap.as_mut(),
-                        );
-
-                        let image_texture = egui::ColorImage::from_rgba_unmultiplied(
-                            [width as _, height as _],
-                            pixmap.data(),
-                        );
-
-                        let handle =
-                            ui.ctx()
-                                .load_texture("svg-image", image_texture, Default::default());
-                        let center_layout = Layout::top_down(Align::Center) // Sets Cross (Horizontal) Align to Center
-                            .with_main_align(Align::Center) // Sets Main (Vertical) Align to Center
-                            .with_main_justify(true); // Forces Main axis (Vertical) to fill space
-
-                        ui.with_layout(center_layout, |ui| {
-                            ui.add(egui::Image::new(&handle));
-                        });
-                    }
-                }
-                Content::Dot(tree) => {
-                    let pixmap_size = tree.size().to_int_size();
-                    let width = pixmap_size.width();
-                    let height = pixmap_size.height();
-
-                    if let Some(mut pixmap) = tiny_skia::Pixmap::new(width, height) {
-                        resvg::render(tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
-
-                        let image_texture = egui::ColorImage::from_rgba_unmultiplied(
-                            [width as _, height as _],
-                            pixmap.data(),
-                        );
-
-                        let handle =
-                            ui.ctx()
-                                .load_texture("svg-image", image_texture, Default::default());
-                        egui::ScrollArea::both().show(ui, |ui| {
-                            ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
-                            ui.add(egui::Image::new(&handle));
-                        });
-                    }
-                }
+        loop {
+            match self.status_receiver.try_recv() {
+                Ok(status) => self.status = status,
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => break,
             }
-        });
+        }
     }
 
-    fn on_exit(&mut self, _gl: Option<&glow::Context>) {
+    fn shut_down(&mut self) {
         self.cancellation_token.cancel();
 
         if let Some(poller) = self.poller.take() {
@@ -353,13 +481,382 @@ impl eframe::App for SvgViewer {
                 Ok(Ok(())) => {}
                 Ok(Err(())) => {}
                 Err(err) => {
-                    eprintln!("Error joining poller: {err:?}");
+                    eprintln!("[pid {}] Error joining poller: {err:?}", self.pid);
                 }
             }
         }
     }
 }
 
+struct SvgViewer {
+    connections: Vec<Connection>,
+    dock_state: DockState<Tab>,
+    config: SharedPollerConfig,
+}
+
+impl SvgViewer {
+    fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        cc.egui_ctx.set_pixels_per_point(1.5);
+
+        let mut pids: Vec<u32> = args().skip(1).filter_map(|arg| arg.parse().ok()).collect();
+        if pids.is_empty() {
+            eprintln!("No PID given on the command line, falling back to pid 0");
+            pids.push(0);
+        }
+
+        let config: SharedPollerConfig = Arc::new(Mutex::new(config::load()));
+
+        let connections: Vec<Connection> = pids
+            .into_iter()
+            .map(|pid| Connection::new(pid, config.clone(), &cc.egui_ctx))
+            .collect();
+
+        let graph_tabs: Vec<Tab> = (0..connections.len()).map(Tab::Graph).collect();
+        let inspector_tabs: Vec<Tab> = (0..connections.len()).map(Tab::Inspector).collect();
+
+        let mut dock_state = DockState::new(graph_tabs);
+        dock_state
+            .main_surface_mut()
+            .split_right(NodeIndex::root(), 0.7, inspector_tabs);
+
+        Self {
+            connections,
+            dock_state,
+            config,
+        }
+    }
+}
+
+/// Top settings bar letting the user tweak refresh cadence, request timeout
+/// and reconnect backoff without restarting the client.
+fn render_settings(ui: &mut egui::Ui, config: &SharedPollerConfig) {
+    let mut config = config.lock().unwrap();
+    ui.horizontal(|ui| {
+        ui.label("Refresh (ms):");
+        ui.add(egui::DragValue::new(&mut config.refresh_interval_ms).range(100..=60_000));
+        ui.label("Request timeout (ms):");
+        ui.add(egui::DragValue::new(&mut config.request_timeout_ms).range(100..=60_000));
+        ui.label("Reconnect backoff (ms):");
+        let max_backoff_ms = config.reconnect_max_backoff_ms;
+        ui.add(
+            egui::DragValue::new(&mut config.reconnect_initial_backoff_ms)
+                .range(100..=max_backoff_ms),
+        );
+        ui.label("max:");
+        let initial_backoff_ms = config.reconnect_initial_backoff_ms;
+        ui.add(
+            egui::DragValue::new(&mut config.reconnect_max_backoff_ms)
+                .range(initial_backoff_ms..=300_000),
+        );
+    });
+}
+
+struct AppTabViewer<'a> {
+    connections: &'a mut [Connection],
+}
+
+impl TabViewer for AppTabViewer<'_> {
+    type Tab = Tab;
+
+    fn title(&mut self, tab: &mut Self::Tab) -> egui::WidgetText {
+        let (label, idx) = match *tab {
+            Tab::Graph(idx) => ("Graph", idx),
+            Tab::Inspector(idx) => ("Inspector", idx),
+        };
+        let connection = &self.connections[idx];
+        let health = match connection.status {
+            ConnectionStatus::Connected => "\u{25cf}",
+            ConnectionStatus::Connecting | ConnectionStatus::Reconnecting => "\u{25d0}",
+            ConnectionStatus::Disconnected => "\u{25cb}",
+        };
+        format!("{health} {label} (pid {})", connection.pid).into()
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, tab: &mut Self::Tab) {
+        match *tab {
+            Tab::Graph(idx) => {
+                let connection = &mut self.connections[idx];
+                render_graph_content(
+                    ui,
+                    &connection.content,
+                    connection.graph_info.as_ref(),
+                    &mut connection.selected_node,
+                )
+            }
+            Tab::Inspector(idx) => render_inspector(ui, &mut self.connections[idx].inspector),
+        }
+    }
+}
+
+fn render_node_detail(ui: &mut egui::Ui, graph_info: &GraphUpdate, node_name: &str) {
+    ui.heading(node_name);
+
+    if let Some(status) = graph_info.statuses.get(node_name) {
+        ui.label("input_read:");
+        ui.monospace(format!("{:?}", status.input_read));
+        ui.label("output_written:");
+        ui.monospace(format!("{:?}", status.output_written));
+
+        ui.label("Recent throughput:");
+        render_sparkline(ui, &status.rate_history);
+    }
+
+    ui.separator();
+    ui.label("Inbound edges:");
+    for edge in graph_info.edges.iter().filter(|e| e.head == node_name) {
+        ui.monospace(format!(
+            "{}[{}] -> [{}] {}",
+            edge.tail,
+            edge.tail_index,
+            edge.head_index,
+            edge_rate_label(edge.rate)
+        ));
+    }
+
+    ui.separator();
+    ui.label("Outbound edges:");
+    for edge in graph_info.edges.iter().filter(|e| e.tail == node_name) {
+        ui.monospace(format!(
+            "[{}] -> {}[{}] {}",
+            edge.tail_index,
+            edge.head,
+            edge.head_index,
+            edge_rate_label(edge.rate)
+        ));
+    }
+}
+
+fn edge_rate_label(rate: Option<f64>) -> String {
+    match rate {
+        Some(rate) => format!("({rate:.1}/s)"),
+        None => String::new(),
+    }
+}
+
+/// Draws a minimal sparkline of `history` (oldest first) in the remaining
+/// horizontal space, capped at a small fixed height.
+fn render_sparkline(ui: &mut egui::Ui, history: &[f64]) {
+    let desired_size = egui::vec2(ui.available_width().min(200.0), 40.0);
+    let (rect, _response) = ui.allocate_exact_size(desired_size, egui::Sense::hover());
+
+    let painter = ui.painter();
+    painter.rect_filled(rect, 2.0, ui.visuals().extreme_bg_color);
+
+    if history.len() < 2 {
+        return;
+    }
+
+    let max = history.iter().copied().fold(0.0_f64, f64::max).max(1.0);
+    let points: Vec<egui::Pos2> = history
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| {
+            let x = rect.left() + (i as f32 / (history.len() - 1) as f32) * rect.width();
+            let y = rect.bottom() - (value / max) as f32 * rect.height();
+            egui::pos2(x, y)
+        })
+        .collect();
+    painter.add(egui::Shape::line(
+        points,
+        egui::Stroke::new(1.5, egui::Color32::from_rgb(0xd6, 0x27, 0x28)),
+    ));
+}
+
+fn hit_test_node(node_boxes: &[NodeBox], local_pos: egui::Pos2) -> Option<String> {
+    node_boxes
+        .iter()
+        .find(|b| {
+            let half_w = b.width / 2.0;
+            let half_h = b.height / 2.0;
+            (local_pos.x as f64 - b.center_x).abs() <= half_w
+                && (local_pos.y as f64 - b.center_y).abs() <= half_h
+        })
+        .map(|b| b.name.clone())
+}
+
+fn render_graph_content(
+    ui: &mut egui::Ui,
+    content: &Content,
+    graph_info: Option<&GraphUpdate>,
+    selected_node: &mut Option<String>,
+) {
+    if let (Some(graph_info), Some(node_name)) = (graph_info, selected_node.as_deref()) {
+        egui::SidePanel::right("graph_node_detail")
+            .resizable(true)
+            .show_inside(ui, |ui| {
+                render_node_detail(ui, graph_info, node_name);
+            });
+    }
+
+    match content {
+        Content::Logo(tree) => {
+            let pixmap_size = tree.size().to_int_size();
+            let available_size = ui.available_size();
+            let zoom = (available_size.x / pixmap_size.width() as f32)
+                .min(available_size.y / pixmap_size.height() as f32)
+                * 0.75;
+            let width = (pixmap_size.width() as f32 * zoom) as u32;
+            let height = (pixmap_size.height() as f32 * zoom) as u32;
+
+            if let Some(mut pixmap) = tiny_skia::Pixmap::new(width, height) {
+                resvg::render(
+                    tree,
+                    tiny_skia::Transform::from_scale(zoom, zoom),
+                    &mut pixmap.as_mut(),
+                );
+
+                let image_texture = egui::ColorImage::from_rgba_unmultiplied(
+                    [width as _, height as _],
+                    pixmap.data(),
+                );
+
+                let handle = ui
+                    .ctx()
+                    .load_texture("svg-image", image_texture, Default::default());
+                let center_layout = Layout::top_down(Align::Center) // Sets Cross (Horizontal) Align to Center
+                    .with_main_align(Align::Center) // Sets Main (Vertical) Align to Center
+                    .with_main_justify(true); // Forces Main axis (Vertical) to fill space
+
+                ui.with_layout(center_layout, |ui| {
+                    ui.add(egui::Image::new(&handle));
+                });
+            }
+        }
+        Content::Dot(tree) => {
+            let pixmap_size = tree.size().to_int_size();
+            let width = pixmap_size.width();
+            let height = pixmap_size.height();
+
+            if let Some(mut pixmap) = tiny_skia::Pixmap::new(width, height) {
+                resvg::render(tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+
+                let image_texture = egui::ColorImage::from_rgba_unmultiplied(
+                    [width as _, height as _],
+                    pixmap.data(),
+                );
+
+                let handle = ui
+                    .ctx()
+                    .load_texture("svg-image", image_texture, Default::default());
+                egui::ScrollArea::both().show(ui, |ui| {
+                    ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                    let response = ui.add(egui::Image::new(&handle).sense(egui::Sense::click()));
+                    if response.clicked() {
+                        if let (Some(graph_info), Some(pos)) =
+                            (graph_info, response.interact_pointer_pos())
+                        {
+                            let local_pos = egui::pos2(
+                                pos.x - response.rect.min.x,
+                                pos.y - response.rect.min.y,
+                            );
+                            *selected_node = hit_test_node(&graph_info.node_boxes, local_pos);
+                        }
+                    }
+                });
+            }
+        }
+    }
+}
+
+fn render_inspector(ui: &mut egui::Ui, inspector: &mut Inspector) {
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut inspector.frozen, "Freeze capture");
+        ui.label("Filter:");
+        ui.text_edit_singleline(&mut inspector.filter);
+        if ui.button("Clear").clicked() {
+            inspector.clear();
+        }
+    });
+
+    ui.separator();
+
+    egui::SidePanel::right("inspector_detail")
+        .resizable(true)
+        .show_inside(ui, |ui| {
+            egui::ScrollArea::vertical()
+                .id_salt("inspector_detail_scroll")
+                .show(ui, |ui| {
+                    match inspector.selected.and_then(|id| inspector.get(id)) {
+                        Some(message) => {
+                            ui.heading(message.name);
+                            ui.label(format!(
+                                "{} bytes, {:.1}s ago",
+                                message.byte_size,
+                                message.timestamp.elapsed().as_secs_f64()
+                            ));
+                            ui.separator();
+                            ui.monospace(&message.tree);
+                        }
+                        None => {
+                            ui.label("Select a message to see its decoded payload.");
+                        }
+                    }
+                });
+        });
+
+    let rows: Vec<(u64, String)> = inspector
+        .iter()
+        .map(|message| {
+            let direction = match message.direction {
+                Direction::Sent => "→",
+                Direction::Received => "←",
+            };
+            (
+                message.id,
+                format!(
+                    "{direction} {} ({} bytes, {:.1}s ago)",
+                    message.name,
+                    message.byte_size,
+                    message.timestamp.elapsed().as_secs_f64()
+                ),
+            )
+        })
+        .collect();
+
+    egui::ScrollArea::vertical()
+        .id_salt("inspector_list_scroll")
+        .show(ui, |ui| {
+            for (id, label) in rows.into_iter().rev() {
+                if ui
+                    .selectable_label(inspector.selected == Some(id), label)
+                    .clicked()
+                {
+                    inspector.selected = Some(id);
+                }
+            }
+        });
+}
+
+impl eframe::App for SvgViewer {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        // https://github.com/emilk/egui/issues/5703
+        if frame.info().cpu_usage.is_none() {
+            return;
+        }
+
+        for connection in &mut self.connections {
+            connection.poll(ctx);
+        }
+
+        egui::TopBottomPanel::top("settings_panel").show(ctx, |ui| {
+            render_settings(ui, &self.config);
+        });
+
+        let mut tab_viewer = AppTabViewer {
+            connections: &mut self.connections,
+        };
+        DockArea::new(&mut self.dock_state)
+            .style(Style::from_egui(ctx.style().as_ref()))
+            .show(ctx, &mut tab_viewer);
+    }
+
+    fn on_exit(&mut self, _gl: Option<&glow::Context>) {
+        for connection in &mut self.connections {
+            connection.shut_down();
+        }
+    }
+}
+
 fn main() -> eframe::Result<()> {
     let native_options = eframe::NativeOptions::default();
     eframe::run_native(
@@ -0,0 +1,164 @@
+//! Turns the cumulative `input_read`/`output_written` counters into an
+//! items/sec throughput rate, so a stalled pipeline stage is visible at a
+//! glance instead of requiring the user to mentally diff raw counters
+//! between refreshes. State lives for the whole connection, inside the
+//! poller thread's retry loop, so rates survive across refreshes but reset
+//! on reconnect.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+/// How many recent samples to keep per node for the detail-panel sparkline.
+const HISTORY_LEN: usize = 30;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Port {
+    Output,
+    Input,
+}
+
+#[derive(Default)]
+pub struct RateTracker {
+    last_port_sample: HashMap<(String, u32, Port), (Instant, u64)>,
+    last_node_sample: HashMap<String, (Instant, u64)>,
+    node_history: HashMap<String, VecDeque<f64>>,
+}
+
+impl RateTracker {
+    fn port_rate(
+        &mut self,
+        port: Port,
+        node: &str,
+        index: u32,
+        value: u64,
+        now: Instant,
+    ) -> Option<f64> {
+        let key = (node.to_owned(), index, port);
+        let rate = self
+            .last_port_sample
+            .get(&key)
+            .and_then(|&(prev_at, prev_value)| rate_between(prev_at, prev_value, now, value));
+        self.last_port_sample.insert(key, (now, value));
+        rate
+    }
+
+    pub fn output_rate(&mut self, node: &str, index: u32, value: u64, now: Instant) -> Option<f64> {
+        self.port_rate(Port::Output, node, index, value, now)
+    }
+
+    pub fn input_rate(&mut self, node: &str, index: u32, value: u64, now: Instant) -> Option<f64> {
+        self.port_rate(Port::Input, node, index, value, now)
+    }
+
+    /// Computes `node`'s total throughput for this refresh from its
+    /// cumulative `total` counter (summed input + output) and appends it to
+    /// the node's rolling history for the sparkline.
+    pub fn record_node_total(&mut self, node: &str, total: u64, now: Instant) {
+        let rate = self
+            .last_node_sample
+            .get(node)
+            .and_then(|&(prev_at, prev_value)| rate_between(prev_at, prev_value, now, total));
+        self.last_node_sample.insert(node.to_owned(), (now, total));
+
+        if let Some(rate) = rate {
+            let history = self.node_history.entry(node.to_owned()).or_default();
+            if history.len() >= HISTORY_LEN {
+                history.pop_front();
+            }
+            history.push_back(rate);
+        }
+    }
+
+    pub fn node_history(&self, node: &str) -> Vec<f64> {
+        self.node_history
+            .get(node)
+            .map(|h| h.iter().copied().collect())
+            .unwrap_or_default()
+    }
+}
+
+fn rate_between(prev_at: Instant, prev_value: u64, now: Instant, value: u64) -> Option<f64> {
+    let elapsed = now.saturating_duration_since(prev_at).as_secs_f64();
+    (elapsed > 0.0).then(|| value.saturating_sub(prev_value) as f64 / elapsed)
+}
+
+/// Formats a cumulative counter for an edge label, appending the rate when
+/// one could be computed (i.e. this isn't the first sample).
+pub fn format_count(value: u64, rate: Option<f64>) -> String {
+    match rate {
+        Some(rate) => format!("{value} ({rate:.1}/s)"),
+        None => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_sample_has_no_rate() {
+        let mut tracker = RateTracker::default();
+        assert_eq!(tracker.output_rate("node", 0, 100, Instant::now()), None);
+    }
+
+    #[test]
+    fn rate_is_delta_over_elapsed_time() {
+        let mut tracker = RateTracker::default();
+        let t0 = Instant::now();
+        let t1 = t0 + Duration::from_secs(10);
+
+        assert_eq!(tracker.output_rate("node", 0, 100, t0), None);
+        assert_eq!(tracker.output_rate("node", 0, 200, t1), Some(10.0));
+    }
+
+    #[test]
+    fn output_and_input_rates_on_the_same_port_index_are_independent() {
+        let mut tracker = RateTracker::default();
+        let t0 = Instant::now();
+        let t1 = t0 + Duration::from_secs(1);
+
+        tracker.output_rate("node", 0, 1000, t0);
+        tracker.input_rate("node", 0, 0, t0);
+
+        assert_eq!(tracker.output_rate("node", 0, 1010, t1), Some(10.0));
+        assert_eq!(tracker.input_rate("node", 0, 5, t1), Some(5.0));
+    }
+
+    #[test]
+    fn counter_reset_does_not_panic_or_go_negative() {
+        let mut tracker = RateTracker::default();
+        let t0 = Instant::now();
+        let t1 = t0 + Duration::from_secs(1);
+
+        tracker.output_rate("node", 0, 100, t0);
+        // Counter dropped (e.g. the monitored process restarted).
+        let rate = tracker.output_rate("node", 0, 5, t1);
+        assert_eq!(rate, Some(0.0));
+    }
+
+    #[test]
+    fn node_history_accumulates_and_caps_at_history_len() {
+        let mut tracker = RateTracker::default();
+        let mut now = Instant::now();
+        tracker.record_node_total("node", 0, now);
+
+        for i in 1..=(HISTORY_LEN as u64 + 5) {
+            now += Duration::from_secs(1);
+            tracker.record_node_total("node", i * 10, now);
+        }
+
+        let history = tracker.node_history("node");
+        assert_eq!(history.len(), HISTORY_LEN);
+        assert!(history
+            .iter()
+            .all(|&rate| (rate - 10.0).abs() < f64::EPSILON));
+    }
+
+    #[test]
+    fn node_history_empty_for_unknown_node() {
+        let tracker = RateTracker::default();
+        assert!(tracker.node_history("missing").is_empty());
+    }
+}